@@ -1,14 +1,34 @@
 use ratatui::{
     layout::Flex,
     prelude::*,
-    widgets::{Block, Paragraph, Row, Table, TableState},
+    widgets::{Block, Cell as RCell, Paragraph, Row, Sparkline, Table, TableState},
 };
 use ratatui_macros::{constraints, horizontal, vertical};
 use tui_big_text::{BigTextBuilder, PixelSize};
 
+mod reflow;
+
+mod run;
+pub use run::App;
+
+mod signal;
+pub use signal::{Chart, RandomWalkSignal, Signal, SineSignal};
+
 /// Application result type.
 pub type AppResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+/// Below this size the dashboard drops its multi-column layout (big-text
+/// title, side-by-side table/avatar) in favor of a compact single-column one.
+const MIN_FULL_WIDTH: u16 = 60;
+const MIN_FULL_HEIGHT: u16 = 20;
+
+/// Indexes into a set of split chunks without panicking — returns a
+/// zero-sized [`Rect`] instead of crashing when `area` was too small for
+/// every constraint to produce a usable region.
+fn rect_at(chunks: &[Rect], index: usize) -> Rect {
+    chunks.get(index).copied().unwrap_or_default()
+}
+
 /// Application.
 #[derive(Debug, Default, Clone)]
 pub struct Dashboard<'a> {
@@ -18,17 +38,20 @@ pub struct Dashboard<'a> {
     avatar_block: Option<Block<'a>>,
     table: Vec<TableItem>,
     table_block: Option<Block<'a>>,
+    table_theme: TableTheme,
     footer: Vec<String>,
+    charts: Vec<Chart>,
+    wrap: bool,
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct TableItem {
-    left: String,
-    right: String,
+    left: TableCell,
+    right: TableCell,
 }
 
 impl TableItem {
-    pub fn new<I: Into<String>>(i: (I, I)) -> Self {
+    pub fn new<I: Into<TableCell>>(i: (I, I)) -> Self {
         Self {
             left: i.0.into(),
             right: i.1.into(),
@@ -38,13 +61,66 @@ impl TableItem {
 
 impl<T> From<(T, T)> for TableItem
 where
-    T: Into<String>,
+    T: Into<TableCell>,
 {
     fn from(value: (T, T)) -> Self {
         Self::new(value)
     }
 }
 
+/// A single table column value: text plus an optional style override.
+///
+/// Falls back to the column's themed style (see
+/// [`DashboardBuilder::column_styles`]) when no style is set here.
+#[derive(Debug, Default, Clone)]
+pub struct TableCell {
+    text: String,
+    style: Option<Style>,
+}
+
+impl TableCell {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), style: None }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl<T: Into<String>> From<T> for TableCell {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Visual theme for [`Dashboard`]'s table. Defaults reproduce the
+/// dashboard's original look: a blue left column, an uppercased bold
+/// light-red right column, and an italic/underlined selection.
+#[derive(Debug, Clone)]
+pub struct TableTheme {
+    table_style: Style,
+    highlight_style: Style,
+    highlight_symbol: String,
+    left_style: Style,
+    right_style: Style,
+    uppercase_right: bool,
+}
+
+impl Default for TableTheme {
+    fn default() -> Self {
+        Self {
+            table_style: Style::new().cyan(),
+            highlight_style: Style::new().italic().bold().underlined(),
+            highlight_symbol: " >> ".to_string(),
+            left_style: Style::new().blue(),
+            right_style: Style::new().light_red().bold(),
+            uppercase_right: true,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct DashboardBuilder<'a>(Dashboard<'a>);
 
@@ -83,11 +159,59 @@ impl<'a> DashboardBuilder<'a> {
         self
     }
 
+    /// Overrides the table's base style (default: cyan).
+    pub fn table_style(mut self, style: Style) -> Self {
+        self.0.table_theme.table_style = style;
+        self
+    }
+
+    /// Overrides the style patched onto the selected row (default:
+    /// italic, bold, underlined).
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.0.table_theme.highlight_style = style;
+        self
+    }
+
+    /// Overrides the selection marker (default: `" >> "`).
+    pub fn highlight_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.0.table_theme.highlight_symbol = symbol.into();
+        self
+    }
+
+    /// Overrides the default per-column styles (left: blue, right: bold
+    /// light-red). A [`TableCell`] with its own style takes precedence.
+    pub fn column_styles(mut self, left: Style, right: Style) -> Self {
+        self.0.table_theme.left_style = left;
+        self.0.table_theme.right_style = right;
+        self
+    }
+
+    /// Toggles uppercasing the right column's text (default: `true`).
+    pub fn uppercase_right(mut self, enabled: bool) -> Self {
+        self.0.table_theme.uppercase_right = enabled;
+        self
+    }
+
     pub fn footer(mut self, footer: Vec<impl Into<String>>) -> Self {
         self.0.footer = footer.into_iter().map(|i| i.into()).collect();
         self
     }
 
+    /// Live time-series panels, sampled from a [`Signal`] each render.
+    pub fn charts(mut self, charts: Vec<Chart>) -> Self {
+        self.0.charts = charts;
+        self
+    }
+
+    /// Wraps the avatar, footer, and table text to the width allocated by
+    /// the layout instead of truncating it (default: `false`). Footer and
+    /// table text is word-wrapped; avatar lines are split by character with
+    /// their whitespace kept.
+    pub fn wrap(mut self, enabled: bool) -> Self {
+        self.0.wrap = enabled;
+        self
+    }
+
     pub fn build(self) -> Dashboard<'a> {
         // let header_area = vertical![==20%, ==70%, ==5%].split()
         self.0
@@ -95,103 +219,251 @@ impl<'a> DashboardBuilder<'a> {
 }
 
 impl Dashboard<'_> {
+    /// Number of rows in the table, used by [`App`] to wrap selection.
+    pub fn table_len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// The table row at `index`, if any, used by [`App`] to report the
+    /// item the user selected.
+    pub fn table_item(&self, index: usize) -> Option<&TableItem> {
+        self.table.get(index)
+    }
+
+    /// Stacks the header, table, avatar, and footer into disjoint bands of
+    /// `area` for the compact layout, so they can each be handed an area
+    /// that doesn't overlap the others (the non-compact layout gets away
+    /// with each `render_*` independently re-deriving its own region from
+    /// the full `area` because their fixed percentages don't collide; in
+    /// compact mode everything shrinks toward the top-left, so that's no
+    /// longer true).
+    fn render_compact(&mut self, area: Rect, buf: &mut Buffer, state: &mut TableState) {
+        let footer_height = if self.footer.is_empty() { 0 } else { 1 };
+        let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(0), Constraint::Length(footer_height)])
+            .split(area);
+        let header_area = rect_at(&chunks, 0);
+        let middle_area = rect_at(&chunks, 1);
+        let footer_area = rect_at(&chunks, 2);
+
+        let avatar_height = if self.avatar.is_empty() { 0 } else { middle_area.height / 3 };
+        let middle_chunks =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(avatar_height)]).split(middle_area);
+        let table_area = rect_at(&middle_chunks, 0);
+        let avatar_area = rect_at(&middle_chunks, 1);
+
+        // No room for a dedicated band: `render_charts` itself bails on a
+        // compact area, so charts are simply omitted on small terminals,
+        // same as everything else that doesn't fit the compact stack.
+        self.render_header(header_area, buf);
+        self.render_table(table_area, buf, state);
+        self.render_avatar(avatar_area, buf);
+        self.render_footer(footer_area, buf);
+    }
+
     fn render_header(&self, area: Rect, buf: &mut Buffer) {
-        let [top_1, top_2] = vertical![==10%, ==5%]
-            .vertical_margin(1)
-            .split(area)
-            .to_vec()
-            .try_into()
-            .unwrap();
+        if Self::is_compact(area) {
+            // The big-text title needs several rows of height to draw its
+            // glyphs; fall back to a single plain line instead of clipping it.
+            let top = rect_at(&vertical![==1].split(area), 0);
+            let heading = format!("{} {}", self.general_title, self.subtitle);
+            Paragraph::new(Line::raw(heading).style(Style::new().light_red().bold()))
+                .centered()
+                .render(top, buf);
+            return;
+        }
 
-        let top_center_1 = horizontal![==(self.general_title.chars().count() as u16 * 4)]
-            .flex(Flex::Center)
-            .split(top_1)[0];
+        let chunks = vertical![==10%, ==5%].vertical_margin(1).split(area);
+        let top_1 = rect_at(&chunks, 0);
+        let top_2 = rect_at(&chunks, 1);
 
-        let top_center_2 = horizontal![==(self.subtitle.chars().count() as u16)]
-            .flex(Flex::Center)
-            .split(top_2)[0];
+        let top_center_1 = rect_at(
+            &horizontal![==(self.general_title.chars().count() as u16 * 4)].flex(Flex::Center).split(top_1),
+            0,
+        );
+        let top_center_2 =
+            rect_at(&horizontal![==(self.subtitle.chars().count() as u16)].flex(Flex::Center).split(top_2), 0);
 
         let title = Line::raw(&self.general_title).style(Style::new().light_red());
-        let title = BigTextBuilder::default()
-            .pixel_size(PixelSize::Quadrant)
-            .lines(vec![title])
-            .build()
-            .unwrap();
+        let title = BigTextBuilder::default().pixel_size(PixelSize::Quadrant).lines(vec![title]).build();
 
         let subtitle = Line::raw(&self.subtitle).style(Style::new().blue().bold());
         title.render(top_center_1, buf);
         subtitle.render(top_center_2, buf);
     }
 
+    fn is_compact(area: Rect) -> bool {
+        area.width < MIN_FULL_WIDTH || area.height < MIN_FULL_HEIGHT
+    }
+
     fn render_table<'a, 'b: 'a>(&'b mut self, area: Rect, buf: &mut Buffer, state: &mut TableState) {
-        let [_, main] = horizontal![==36%, ==57%]
-            .horizontal_margin(3)
-            .split(area)
-            .to_vec()
-            .try_into()
-            .unwrap();
-
-        let lines = {
-            let to_line = |(idx, a): (usize, &'a TableItem)| {
-                let style = match state.selected() {
-                    Some(i) if i == idx => Style::default().italic().bold().underlined(),
-                    _ => Style::default(),
+        let compact = Self::is_compact(area);
+        let main = if compact {
+            area
+        } else {
+            rect_at(&horizontal![==36%, ==57%].horizontal_margin(3).split(area), 1)
+        };
+
+        let theme = &self.table_theme;
+        let widths = constraints![==95%, ==5%];
+
+        let (rows, table_height): (Vec<Row>, u16) = if self.wrap {
+            // `Table` lays its columns out inside the block, after the
+            // highlight-symbol gutter (reserved while a row is selected) and
+            // one column of spacing, then splits what's left 95%/5%; wrap
+            // each column to the width it actually ends up with.
+            let block = self.table_block.clone().unwrap_or(Block::bordered());
+            let symbol_width =
+                if state.selected().is_some() { Line::raw(theme.highlight_symbol.as_str()).width() } else { 0 };
+            let columns_width = (block.inner(main).width as usize).saturating_sub(symbol_width + 1);
+            let left_width = (columns_width * 95 / 100).max(1);
+            let right_width = (columns_width * 5 / 100).max(1);
+
+            let mut total_height = 0u16;
+            let rows = self
+                .table
+                .iter()
+                .enumerate()
+                .map(|(idx, a)| {
+                    let highlight = match state.selected() {
+                        Some(i) if i == idx => theme.highlight_style,
+                        _ => Style::default(),
+                    };
+                    let left_style = a.left.style.unwrap_or(theme.left_style).patch(highlight);
+                    let right_style = a.right.style.unwrap_or(theme.right_style).patch(highlight);
+                    let right_text =
+                        if theme.uppercase_right { a.right.text.to_uppercase() } else { a.right.text.clone() };
+
+                    let left_lines = reflow::wrap(&a.left.text, left_width);
+                    let right_lines = reflow::wrap(&right_text, right_width);
+                    let row_height = left_lines.len().max(right_lines.len()).max(1) as u16;
+                    total_height += row_height;
+
+                    let to_text = |lines: Vec<String>, style: Style| {
+                        Text::from(lines.into_iter().map(|l| Line::styled(l, style)).collect::<Vec<_>>())
+                    };
+                    let left_cell = RCell::from(to_text(left_lines, left_style));
+                    let right_cell = RCell::from(to_text(right_lines, right_style));
+                    Row::new(vec![left_cell, right_cell]).height(row_height).style(highlight)
+                })
+                .collect();
+            (rows, total_height)
+        } else {
+            let lines = {
+                let to_line = |(idx, a): (usize, &'a TableItem)| {
+                    let highlight = match state.selected() {
+                        Some(i) if i == idx => theme.highlight_style,
+                        _ => Style::default(),
+                    };
+                    let left_style = a.left.style.unwrap_or(theme.left_style).patch(highlight);
+                    let right_style = a.right.style.unwrap_or(theme.right_style).patch(highlight);
+                    let right_text =
+                        if theme.uppercase_right { a.right.text.to_uppercase() } else { a.right.text.clone() };
+                    let description = Span::styled(a.left.text.as_str(), left_style);
+                    let keyboard = Span::styled(right_text, right_style);
+                    Line::default().spans(vec![description, keyboard]).style(highlight)
                 };
-                let description = a.left.as_str().blue().style(style);
-                let keyboard = a.right.as_str().to_uppercase().light_red().bold();
-                Line::default().spans(vec![description, keyboard]).style(style)
+                self.table.iter().enumerate().map(to_line)
             };
-            self.table.iter().enumerate().map(to_line)
+            let table_height = lines.clone().count() as u16;
+            (lines.map(Row::new).collect(), table_height)
         };
 
-        let mut table_width = 0;
-        let mut table_height = 0;
-        for i in lines.clone() {
-            table_width = table_width.max(i.width() + 2);
-            table_height += 1;
-        }
+        // Shrink the viewport to whatever the area can actually hold instead
+        // of requesting more rows than exist and clipping silently.
+        let max_table_height = main.height.saturating_sub(2).max(1);
+        let table_height = table_height.min(max_table_height);
 
         let table = {
-            let rows = lines.map(Row::new);
-            let widths = constraints![==95%, ==5%];
             let block = self.table_block.clone().unwrap_or(Block::bordered());
-            Table::new(rows, widths).highlight_symbol(" >> ").cyan().block(block)
+            Table::new(rows, widths)
+                .highlight_symbol(theme.highlight_symbol.as_str())
+                .style(theme.table_style)
+                .block(block)
         };
 
-        let [_, main] = vertical![==20%, ==table_height + 2].split(main).to_vec().try_into().unwrap();
+        let main = if compact {
+            rect_at(&vertical![==table_height + 2].split(main), 0)
+        } else {
+            rect_at(&vertical![==20%, ==table_height + 2].split(main), 1)
+        };
+
+        // `Table::render` already keeps `state.selected()` inside the
+        // viewport on its own (via its internal row-bounds tracking), so
+        // there's nothing left for this method to reconcile beforehand.
         StatefulWidget::render(table, main, buf, state);
     }
 
     fn render_avatar(&self, area: Rect, buf: &mut Buffer) {
-        let lines = self.avatar.lines().map(|i| Line::from(i.light_blue())).collect::<Vec<_>>();
+        if self.avatar.is_empty() {
+            return;
+        }
+
+        let lines = if self.wrap {
+            // Cap the avatar to a share of the area instead of letting its
+            // natural (possibly very wide) art dictate the layout.
+            let max_width = (area.width as usize * 2 / 5).max(1);
+            self.avatar
+                .lines()
+                .flat_map(|i| reflow::wrap_preserving_whitespace(i, max_width))
+                .map(|i| Line::from(i.light_blue()))
+                .collect::<Vec<_>>()
+        } else {
+            self.avatar.lines().map(|i| Line::from(i.light_blue())).collect::<Vec<_>>()
+        };
         let mut avatar_width = 0;
         let mut avatar_height = 0;
         for line in lines.iter() {
             avatar_width = avatar_width.max(line.width());
             avatar_height += 1;
         }
-        let [_, left] = horizontal![==8%, ==avatar_width as u16]
-            .split(area)
-            .to_vec()
-            .try_into()
-            .unwrap();
-        let [_, left] = vertical![==20%, ==avatar_height as u16]
-            .split(left)
-            .to_vec()
-            .try_into()
-            .unwrap();
+
+        if Self::is_compact(area) {
+            // Not enough room to reserve the avatar its own columns: clip it
+            // into whatever corner of `area` is available instead of hiding it.
+            let width = (avatar_width as u16).min(area.width);
+            let height = (avatar_height as u16).min(area.height);
+            if width == 0 || height == 0 {
+                return;
+            }
+            let clipped = Rect { x: area.x, y: area.y, width, height };
+            let block = self.avatar_block.clone().unwrap_or(Block::bordered());
+            Paragraph::new(lines).block(block).render(clipped, buf);
+            return;
+        }
+
+        let left = rect_at(&horizontal![==8%, ==avatar_width as u16].split(area), 1);
+        let left = rect_at(&vertical![==20%, ==avatar_height as u16].split(left), 1);
         let block = self.avatar_block.clone().unwrap_or(Block::bordered());
         let avatar = Paragraph::new(lines).block(block);
         avatar.render(left, buf);
     }
+    fn render_charts(&self, area: Rect, buf: &mut Buffer) {
+        if self.charts.is_empty() || Self::is_compact(area) {
+            return;
+        }
+
+        let right = rect_at(&horizontal![==65%, ==33%].horizontal_margin(1).split(area), 1);
+
+        let chart_height = 5;
+        let rows = Layout::vertical(vec![Constraint::Length(chart_height); self.charts.len()]).split(right);
+
+        for (chart, row) in self.charts.iter().zip(rows.iter()) {
+            chart.sample();
+            let data = chart.data();
+            let block = Block::bordered().title(chart.title().to_owned());
+            let sparkline = Sparkline::default().block(block).data(&data).style(Style::new().light_green());
+            sparkline.render(*row, buf);
+        }
+    }
+
     fn render_footer(&self, area: Rect, buf: &mut Buffer) {
-        let [_, bottom] = vertical![==95%, ==5%]
-            .vertical_margin(1)
-            .split(area)
-            .to_vec()
-            .try_into()
-            .unwrap();
-        let lines = self.footer.iter().map(Line::raw).collect::<Vec<_>>();
+        let bottom = rect_at(&vertical![==95%, ==5%].vertical_margin(1).split(area), 1);
+        let lines = if self.wrap {
+            let width = bottom.width as usize;
+            self.footer.iter().flat_map(|f| reflow::wrap(f, width)).map(Line::raw).collect::<Vec<_>>()
+        } else {
+            self.footer.iter().map(Line::raw).collect::<Vec<_>>()
+        };
         let footer = Paragraph::new(lines).centered().bold().light_cyan().italic();
         footer.render(bottom, buf);
     }
@@ -201,9 +473,15 @@ impl StatefulWidget for Dashboard<'_> {
     type State = TableState;
 
     fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if Self::is_compact(area) {
+            self.render_compact(area, buf, state);
+            return;
+        }
+
         self.render_header(area, buf);
         self.render_table(area, buf, state);
         self.render_avatar(area, buf);
+        self.render_charts(area, buf);
         self.render_footer(area, buf);
     }
 }
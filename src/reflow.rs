@@ -0,0 +1,138 @@
+use ratatui::text::Span;
+
+/// Display width of `s`, matching how the rest of the layout sizes things
+/// (see `Line::width()`/`avatar_width` in `lib.rs`) so a
+/// wrapped line can't still overflow its allocated column width.
+fn display_width(s: &str) -> usize {
+    Span::raw(s).width()
+}
+
+fn char_width(c: char) -> usize {
+    let mut buf = [0; 4];
+    display_width(c.encode_utf8(&mut buf))
+}
+
+/// Word-wraps `text` to `width` display columns, falling back to a
+/// character split for any single word wider than `width` on its own (e.g.
+/// a long token with no natural break point).
+///
+/// This collapses runs of whitespace between words, which is the right
+/// trade-off for prose (footer lines, table cells) but not for art where
+/// whitespace is part of the content — use [`wrap_preserving_whitespace`]
+/// for that.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    if text.is_empty() {
+        return vec![String::new()];
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in text.split_whitespace() {
+        for chunk in hard_wrap(word, width) {
+            let chunk_width = display_width(&chunk);
+            if current.is_empty() {
+                current = chunk;
+                current_width = chunk_width;
+            } else if current_width + 1 + chunk_width <= width {
+                current.push(' ');
+                current.push_str(&chunk);
+                current_width += 1 + chunk_width;
+            } else {
+                lines.push(std::mem::take(&mut current));
+                current = chunk;
+                current_width = chunk_width;
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// Wraps a single line to `width` display columns without touching its
+/// whitespace: a line that already fits is passed through byte-for-byte,
+/// and only a line wider than `width` is hard-split, one character at a
+/// time. Suitable for ASCII/ANSI art, where internal spacing is content
+/// (alignment, gaps, indentation) rather than filler between words.
+pub(crate) fn wrap_preserving_whitespace(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    if display_width(text) <= width {
+        return vec![text.to_string()];
+    }
+    hard_wrap(text, width)
+}
+
+/// Splits a single unbreakable run of text into `width`-wide chunks, one
+/// character at a time (a grapheme-cluster split would be nicer, but isn't
+/// worth a new dependency for this fallback path).
+fn hard_wrap(text: &str, width: usize) -> Vec<String> {
+    if display_width(text) <= width {
+        return vec![text.to_string()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for c in text.chars() {
+        let w = char_width(c);
+        if current_width + w > width && !current.is_empty() {
+            chunks.push(std::mem::take(&mut current));
+            current_width = 0;
+        }
+        current.push(c);
+        current_width += w;
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_empty_string_yields_one_empty_line() {
+        assert_eq!(wrap("", 10), vec![String::new()]);
+    }
+
+    #[test]
+    fn wrap_splits_on_word_boundaries() {
+        assert_eq!(wrap("the quick brown fox", 10), vec!["the quick", "brown fox"]);
+    }
+
+    #[test]
+    fn wrap_hard_splits_a_single_overlong_word() {
+        assert_eq!(wrap("supercalifragilistic", 5), vec!["super", "calif", "ragil", "istic"]);
+    }
+
+    #[test]
+    fn wrap_accounts_for_double_width_chars() {
+        // Each CJK character below is 2 columns wide, so only 2 fit per line.
+        assert_eq!(wrap("你好世界", 4), vec!["你好", "世界"]);
+    }
+
+    #[test]
+    fn wrap_preserving_whitespace_passes_through_lines_that_already_fit() {
+        assert_eq!(wrap_preserving_whitespace("  /\\_/\\  ", 20), vec!["  /\\_/\\  "]);
+    }
+
+    #[test]
+    fn wrap_preserving_whitespace_hard_splits_overlong_lines_without_collapsing_gaps() {
+        assert_eq!(wrap_preserving_whitespace("a  b  c  d", 4), vec!["a  b", "  c ", " d"]);
+    }
+
+    #[test]
+    fn wrap_preserving_whitespace_accounts_for_double_width_chars() {
+        assert_eq!(wrap_preserving_whitespace("你好世界", 4), vec!["你好", "世界"]);
+    }
+}
@@ -0,0 +1,118 @@
+use std::io::{self, Stdout};
+use std::time::Duration;
+
+use ratatui::crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{prelude::*, widgets::TableState};
+
+use crate::{AppResult, Dashboard, TableItem};
+
+type DashboardTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+/// How often the loop redraws (and so samples charts) when the user isn't
+/// pressing anything, so live panels keep moving instead of freezing
+/// between keystrokes.
+const TICK_RATE: Duration = Duration::from_millis(250);
+
+/// Drives a [`Dashboard`] in a terminal: raw mode, the alternate screen, the
+/// input/redraw loop, and panic-safe teardown.
+pub struct App<'a> {
+    dashboard: Dashboard<'a>,
+    state: TableState,
+}
+
+impl<'a> App<'a> {
+    pub fn new(dashboard: Dashboard<'a>) -> Self {
+        Self { dashboard, state: TableState::default().with_selected(Some(0)) }
+    }
+
+    /// Enters raw mode and the alternate screen, runs the event loop until
+    /// the user quits or selects an item, then restores the terminal.
+    pub fn run(mut self) -> AppResult<Option<TableItem>> {
+        let mut terminal = Self::init_terminal()?;
+        let result = self.event_loop(&mut terminal);
+        Self::restore_terminal()?;
+        result
+    }
+
+    fn event_loop(&mut self, terminal: &mut DashboardTerminal) -> AppResult<Option<TableItem>> {
+        loop {
+            terminal.draw(|frame| frame.render_stateful_widget(self.dashboard.clone(), frame.area(), &mut self.state))?;
+
+            if !event::poll(TICK_RATE)? {
+                continue;
+            }
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => self.select_previous(),
+                KeyCode::Down | KeyCode::Char('j') => self.select_next(),
+                KeyCode::Enter => return Ok(self.selected_item()),
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
+                _ => {}
+            }
+        }
+    }
+
+    fn select_previous(&mut self) {
+        let len = self.dashboard.table_len();
+        if len == 0 {
+            return;
+        }
+        let next = match self.state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+        self.state.select(Some(next));
+    }
+
+    fn select_next(&mut self) {
+        let len = self.dashboard.table_len();
+        if len == 0 {
+            return;
+        }
+        let next = match self.state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        self.state.select(Some(next));
+    }
+
+    fn selected_item(&self) -> Option<TableItem> {
+        self.state.selected().and_then(|i| self.dashboard.table_item(i)).cloned()
+    }
+
+    fn init_terminal() -> AppResult<DashboardTerminal> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen)?;
+        install_panic_hook();
+        Ok(Terminal::new(CrosstermBackend::new(io::stdout()))?)
+    }
+
+    fn restore_terminal() -> AppResult<()> {
+        disable_raw_mode()?;
+        execute!(io::stdout(), LeaveAlternateScreen)?;
+        Ok(())
+    }
+}
+
+/// Makes sure a panic mid-render doesn't leave the user's terminal stuck in
+/// raw mode / the alternate screen: restore it first, then defer to the
+/// default hook so the panic message still prints normally.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+        default_hook(info);
+    }));
+}
@@ -0,0 +1,160 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+/// A live data source, sampled once per [`Dashboard`](crate::Dashboard) render.
+///
+/// Implement this to feed a chart panel from CPU/network/custom metrics;
+/// [`SineSignal`] and [`RandomWalkSignal`] are built-in generators for demos.
+pub trait Signal {
+    /// Produce the next sample.
+    fn sample(&mut self) -> u64;
+}
+
+/// A smooth oscillating signal.
+#[derive(Debug, Clone)]
+pub struct SineSignal {
+    amplitude: u64,
+    step: f64,
+    phase: f64,
+}
+
+impl SineSignal {
+    pub fn new(amplitude: u64, step: f64) -> Self {
+        Self { amplitude, step, phase: 0.0 }
+    }
+}
+
+impl Signal for SineSignal {
+    fn sample(&mut self) -> u64 {
+        self.phase += self.step;
+        (((self.phase.sin() + 1.0) / 2.0) * self.amplitude as f64).round() as u64
+    }
+}
+
+/// A bounded random walk, driven by a small xorshift PRNG so a single demo
+/// signal doesn't need to pull in a `rand` dependency.
+#[derive(Debug, Clone)]
+pub struct RandomWalkSignal {
+    value: i64,
+    bound: i64,
+    state: u64,
+}
+
+impl RandomWalkSignal {
+    pub fn new(start: u64, bound: u64) -> Self {
+        Self { value: start as i64, bound: bound as i64, state: start.max(1) }
+    }
+
+    fn next_step(&mut self) -> i64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        (self.state % 3) as i64 - 1
+    }
+}
+
+impl Signal for RandomWalkSignal {
+    fn sample(&mut self) -> u64 {
+        let step = self.next_step();
+        self.value = (self.value + step).clamp(0, self.bound);
+        self.value as u64
+    }
+}
+
+struct ChartInner {
+    signal: Box<dyn Signal>,
+    buffer: VecDeque<u64>,
+    capacity: usize,
+}
+
+/// A named live panel: a [`Signal`] sampled into a bounded ring buffer on
+/// each render.
+///
+/// `Chart` is cheaply [`Clone`] — clones share the same underlying buffer
+/// and signal through an [`Rc`], since [`Dashboard`](crate::Dashboard) is
+/// cloned per frame but the sampled data must persist across frames.
+#[derive(Clone)]
+pub struct Chart {
+    title: String,
+    inner: Rc<RefCell<ChartInner>>,
+}
+
+impl Chart {
+    /// Creates a chart panel that keeps the last `capacity` samples.
+    pub fn new(title: impl Into<String>, signal: impl Signal + 'static, capacity: usize) -> Self {
+        Self {
+            title: title.into(),
+            inner: Rc::new(RefCell::new(ChartInner {
+                signal: Box::new(signal),
+                buffer: VecDeque::with_capacity(capacity),
+                capacity: capacity.max(1),
+            })),
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Samples the signal once, evicting the oldest point if the buffer is full.
+    pub(crate) fn sample(&self) {
+        let mut inner = self.inner.borrow_mut();
+        if inner.buffer.len() == inner.capacity {
+            inner.buffer.pop_front();
+        }
+        let point = inner.signal.sample();
+        inner.buffer.push_back(point);
+    }
+
+    pub(crate) fn data(&self) -> Vec<u64> {
+        self.inner.borrow().buffer.iter().copied().collect()
+    }
+}
+
+impl fmt::Debug for Chart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chart").field("title", &self.title).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sine_signal_stays_within_amplitude() {
+        let mut signal = SineSignal::new(10, 0.3);
+        for _ in 0..100 {
+            assert!(signal.sample() <= 10);
+        }
+    }
+
+    #[test]
+    fn random_walk_signal_stays_within_bound() {
+        let mut signal = RandomWalkSignal::new(5, 10);
+        for _ in 0..1000 {
+            assert!(signal.sample() <= 10);
+        }
+    }
+
+    #[test]
+    fn random_walk_signal_does_not_go_negative_from_a_zero_start() {
+        let mut signal = RandomWalkSignal::new(0, 10);
+        for _ in 0..1000 {
+            // A negative `value` would wrap to a huge u64 in the cast, so
+            // staying within the bound shows the clamp kept it non-negative.
+            assert!(signal.sample() <= 10);
+        }
+    }
+
+    #[test]
+    fn chart_evicts_oldest_sample_once_capacity_is_exceeded() {
+        let chart = Chart::new("test", SineSignal::new(0, 0.0), 3);
+        for _ in 0..5 {
+            chart.sample();
+        }
+        assert_eq!(chart.data().len(), 3);
+    }
+}